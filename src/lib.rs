@@ -0,0 +1,7 @@
+pub mod media_playlist;
+pub mod media_segment;
+pub mod tags;
+pub mod types;
+
+pub use media_playlist::MediaPlaylist;
+pub use media_segment::MediaSegment;
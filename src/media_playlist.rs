@@ -0,0 +1,184 @@
+use std::fmt;
+
+use derive_builder::Builder;
+use shorthand::ShortHand;
+
+use crate::tags::{ExtXPreloadHint, ExtXRenditionReport, ExtXServerControl};
+use crate::types::ProtocolVersion;
+use crate::{MediaSegment, RequiredVersion};
+
+/// A [`MediaPlaylist`] contains a list of [`MediaSegment`]s, which when
+/// played in sequence, will play the multimedia presentation.
+#[derive(ShortHand, Debug, Clone, Builder, PartialEq)]
+#[builder(setter(strip_option))]
+#[shorthand(enable(must_use))]
+pub struct MediaPlaylist {
+    /// The [`MediaSegment`]s that make up this [`MediaPlaylist`].
+    ///
+    /// ## Note
+    ///
+    /// This field is optional and defaults to an empty list.
+    #[builder(default, setter(into))]
+    #[shorthand(enable(skip))]
+    pub segments: Vec<MediaSegment>,
+    /// This field allows the server to tune how aggressively a client
+    /// should reload this [`MediaPlaylist`], as introduced by the
+    /// Low-Latency HLS extension.
+    ///
+    /// ## Note
+    ///
+    /// This field is optional.
+    #[builder(default)]
+    #[shorthand(enable(skip))]
+    pub server_control: Option<ExtXServerControl>,
+    /// This field lists resources that a client can start fetching before
+    /// they are fully announced elsewhere in this [`MediaPlaylist`], as
+    /// introduced by the Low-Latency HLS extension.
+    ///
+    /// ## Note
+    ///
+    /// This field is optional and most commonly empty outside of the live
+    /// edge of the presentation.
+    #[builder(default, setter(into))]
+    #[shorthand(enable(skip))]
+    pub preload_hints: Vec<ExtXPreloadHint>,
+    /// This field lists the other renditions of the presentation, for which
+    /// a client can retrieve an up-to-date rendition report, as introduced
+    /// by the Low-Latency HLS extension.
+    ///
+    /// ## Note
+    ///
+    /// This field is optional.
+    #[builder(default, setter(into))]
+    #[shorthand(enable(skip))]
+    pub rendition_reports: Vec<ExtXRenditionReport>,
+}
+
+impl MediaPlaylist {
+    /// Returns a builder for a [`MediaPlaylist`].
+    #[must_use]
+    #[inline]
+    pub fn builder() -> MediaPlaylistBuilder { MediaPlaylistBuilder::default() }
+}
+
+impl MediaPlaylistBuilder {
+    /// Pushes a [`MediaSegment`].
+    pub fn push_segment<VALUE: Into<MediaSegment>>(&mut self, value: VALUE) -> &mut Self {
+        if let Some(segments) = &mut self.segments {
+            segments.push(value.into());
+        } else {
+            self.segments = Some(vec![value.into()]);
+        }
+
+        self
+    }
+
+    /// Pushes an [`ExtXPreloadHint`] tag.
+    pub fn push_preload_hint<VALUE: Into<ExtXPreloadHint>>(&mut self, value: VALUE) -> &mut Self {
+        if let Some(preload_hints) = &mut self.preload_hints {
+            preload_hints.push(value.into());
+        } else {
+            self.preload_hints = Some(vec![value.into()]);
+        }
+
+        self
+    }
+
+    /// Pushes an [`ExtXRenditionReport`] tag.
+    pub fn push_rendition_report<VALUE: Into<ExtXRenditionReport>>(
+        &mut self,
+        value: VALUE,
+    ) -> &mut Self {
+        if let Some(rendition_reports) = &mut self.rendition_reports {
+            rendition_reports.push(value.into());
+        } else {
+            self.rendition_reports = Some(vec![value.into()]);
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for MediaPlaylist {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(value) = &self.server_control {
+            writeln!(f, "{}", value)?;
+        }
+
+        for value in &self.preload_hints {
+            writeln!(f, "{}", value)?;
+        }
+
+        for value in &self.rendition_reports {
+            writeln!(f, "{}", value)?;
+        }
+
+        // The keys of a segment are only printed when they differ from the keys
+        // of the previous segment.
+        let mut previous_keys = None;
+
+        for value in &self.segments {
+            if previous_keys != Some(&value.keys) {
+                for key in &value.keys {
+                    writeln!(f, "{}", key)?;
+                }
+
+                previous_keys = Some(&value.keys);
+            }
+
+            write!(f, "{}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl RequiredVersion for MediaPlaylist {
+    fn required_version(&self) -> ProtocolVersion {
+        required_version![
+            self.segments,
+            self.server_control,
+            self.preload_hints,
+            self.rendition_reports
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        let mut playlist = MediaPlaylist::builder();
+        playlist.push_preload_hint(ExtXPreloadHint::new(
+            crate::tags::PreloadHintType::Part,
+            "part.5.mp4",
+        ));
+        playlist.push_rendition_report(ExtXRenditionReport::new("low.m3u8"));
+
+        assert_eq!(
+            playlist.build().unwrap().to_string(),
+            concat!(
+                "#EXT-X-PRELOAD-HINT:TYPE=PART,URI=\"part.5.mp4\"\n",
+                "#EXT-X-RENDITION-REPORT:URI=\"low.m3u8\"\n",
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_with_server_control() {
+        let mut server_control = ExtXServerControl::new();
+        server_control.set_can_block_reload(true);
+
+        let mut playlist = MediaPlaylist::builder();
+        playlist.server_control(server_control);
+
+        assert_eq!(
+            playlist.build().unwrap().to_string(),
+            "#EXT-X-SERVER-CONTROL:CAN-BLOCK-RELOAD=YES\n".to_string()
+        );
+    }
+}
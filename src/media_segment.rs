@@ -4,7 +4,8 @@ use derive_builder::Builder;
 use shorthand::ShortHand;
 
 use crate::tags::{
-    ExtInf, ExtXByteRange, ExtXDateRange, ExtXDiscontinuity, ExtXKey, ExtXMap, ExtXProgramDateTime,
+    ExtInf, ExtXBitrate, ExtXByteRange, ExtXDateRange, ExtXDiscontinuity, ExtXGap, ExtXKey, ExtXMap,
+    ExtXPart, ExtXProgramDateTime,
 };
 use crate::types::{DecryptionKey, ProtocolVersion};
 use crate::{Decryptable, RequiredVersion};
@@ -116,6 +117,16 @@ pub struct MediaSegment {
     #[builder(default)]
     #[shorthand(enable(skip))]
     pub date_range: Option<ExtXDateRange>,
+    /// This field lists the partial segments, that make up this
+    /// `MediaSegment`, as introduced by the Low-Latency HLS extension.
+    ///
+    /// ## Note
+    ///
+    /// This field is optional and most commonly empty for a `MediaSegment`
+    /// that has already been fully published.
+    #[builder(default, setter(into))]
+    #[shorthand(enable(skip))]
+    pub parts: Vec<ExtXPart>,
     /// This field indicates a discontinuity between the `MediaSegment` that
     /// follows it and the one that preceded it.
     ///
@@ -133,6 +144,15 @@ pub struct MediaSegment {
     #[builder(default)]
     #[shorthand(enable(skip))]
     pub has_discontinuity: bool,
+    /// This field indicates that the resource of a `MediaSegment` is not
+    /// available and should not be loaded by clients.
+    ///
+    /// ## Note
+    ///
+    /// This field defaults to `false`.
+    #[builder(default)]
+    #[shorthand(enable(skip))]
+    pub gap: bool,
     /// This field associates the first sample of a media segment with an
     /// absolute date and/or time.
     ///
@@ -142,6 +162,16 @@ pub struct MediaSegment {
     #[builder(default)]
     #[shorthand(enable(skip))]
     pub program_date_time: Option<ExtXProgramDateTime>,
+    /// This field indicates the approximate bit rate of a `MediaSegment`.
+    ///
+    /// ## Note
+    ///
+    /// This field is optional. Per the HLS spec this value applies to the
+    /// `MediaSegment` it is set on and every following `MediaSegment`, until
+    /// a subsequent `MediaSegment` specifies its own [`ExtXBitrate`].
+    #[builder(default, setter(into))]
+    #[shorthand(enable(skip))]
+    pub bitrate: Option<ExtXBitrate>,
     /// This field indicates the duration of a media segment.
     ///
     /// ## Note
@@ -196,6 +226,17 @@ impl MediaSegmentBuilder {
         self
     }
 
+    /// Pushes an [`ExtXPart`] tag.
+    pub fn push_part<VALUE: Into<ExtXPart>>(&mut self, value: VALUE) -> &mut Self {
+        if let Some(parts) = &mut self.parts {
+            parts.push(value.into());
+        } else {
+            self.parts = Some(vec![value.into()]);
+        }
+
+        self
+    }
+
     /// The number of a [`MediaSegment`]. Normally this should not be set
     /// explicitly, because the [`MediaPlaylist::builder`] will automatically
     /// apply the correct number.
@@ -229,10 +270,22 @@ impl fmt::Display for MediaSegment {
             writeln!(f, "{}", ExtXDiscontinuity)?;
         }
 
+        if self.gap {
+            writeln!(f, "{}", ExtXGap)?;
+        }
+
         if let Some(value) = &self.program_date_time {
             writeln!(f, "{}", value)?;
         }
 
+        for value in &self.parts {
+            writeln!(f, "{}", value)?;
+        }
+
+        if let Some(value) = &self.bitrate {
+            writeln!(f, "{}", value)?;
+        }
+
         writeln!(f, "{}", self.inf)?;
         writeln!(f, "{}", self.uri)?;
         Ok(())
@@ -253,7 +306,16 @@ impl RequiredVersion for MediaSegment {
                     None
                 }
             },
+            {
+                if self.gap {
+                    Some(ExtXGap)
+                } else {
+                    None
+                }
+            },
             self.program_date_time,
+            self.parts,
+            self.bitrate,
             self.inf
         ]
     }
@@ -279,6 +341,9 @@ mod tests {
                 .map(ExtXMap::new("https://www.example.com/"))
                 .byte_range(ExtXByteRange::from(5..25))
                 .has_discontinuity(true)
+                .gap(true)
+                .push_part(ExtXPart::new("part.1.mp4", Duration::from_secs_f64(0.5)))
+                .bitrate(ExtXBitrate::new(500))
                 .inf(ExtInf::new(Duration::from_secs(4)))
                 .uri("http://www.uri.com/")
                 .build()
@@ -288,10 +353,27 @@ mod tests {
                 "#EXT-X-MAP:URI=\"https://www.example.com/\"\n",
                 "#EXT-X-BYTERANGE:20@5\n",
                 "#EXT-X-DISCONTINUITY\n",
+                "#EXT-X-GAP\n",
+                "#EXT-X-PART:URI=\"part.1.mp4\",DURATION=0.5\n",
+                "#EXT-X-BITRATE:500\n",
                 "#EXTINF:4,\n",
                 "http://www.uri.com/\n"
             )
             .to_string()
         );
     }
+
+    #[test]
+    fn test_required_version_with_bitrate() {
+        assert_eq!(
+            MediaSegment::builder()
+                .bitrate(ExtXBitrate::new(500))
+                .inf(ExtInf::new(Duration::from_secs(4)))
+                .uri("http://www.uri.com/")
+                .build()
+                .unwrap()
+                .required_version(),
+            ProtocolVersion::V1
+        );
+    }
 }
@@ -4,7 +4,7 @@ use std::str::FromStr;
 use getset::{Getters, MutGetters, Setters};
 
 use crate::attribute::AttributePairs;
-use crate::types::{DecimalResolution, HdcpLevel, ProtocolVersion};
+use crate::types::{DecimalFloatingPoint, DecimalResolution, HdcpLevel, ProtocolVersion, VideoRange};
 use crate::utils::parse_u64;
 use crate::utils::{quote, tag, unquote};
 use crate::Error;
@@ -46,6 +46,24 @@ pub struct ExtXIFrameStreamInf {
     #[get_mut = "pub"]
     /// The group identifier for the video in the variant stream.
     video: Option<String>,
+    #[get = "pub"]
+    #[set = "pub"]
+    #[get_mut = "pub"]
+    /// The video dynamic range of the variant stream.
+    video_range: Option<VideoRange>,
+    #[get = "pub"]
+    #[set = "pub"]
+    #[get_mut = "pub"]
+    /// An abstract, relative measure of the playback quality-of-experience of
+    /// the variant stream, used to rank variants of otherwise identical
+    /// `BANDWIDTH`.
+    score: Option<DecimalFloatingPoint>,
+    #[get = "pub"]
+    #[set = "pub"]
+    #[get_mut = "pub"]
+    /// A stable identifier for the URI of the variant stream, that remains
+    /// unchanged between playlist reloads.
+    stable_variant_id: Option<String>,
 }
 
 impl ExtXIFrameStreamInf {
@@ -61,6 +79,9 @@ impl ExtXIFrameStreamInf {
             resolution: None,
             hdcp_level: None,
             video: None,
+            video_range: None,
+            score: None,
+            stable_variant_id: None,
         }
     }
 
@@ -110,6 +131,15 @@ impl fmt::Display for ExtXIFrameStreamInf {
         if let Some(value) = &self.video {
             write!(f, ",VIDEO={}", quote(value))?;
         }
+        if let Some(value) = &self.video_range {
+            write!(f, ",VIDEO-RANGE={}", value)?;
+        }
+        if let Some(value) = &self.score {
+            write!(f, ",SCORE={}", value)?;
+        }
+        if let Some(value) = &self.stable_variant_id {
+            write!(f, ",STABLE-VARIANT-ID={}", quote(value))?;
+        }
         Ok(())
     }
 }
@@ -127,6 +157,9 @@ impl FromStr for ExtXIFrameStreamInf {
         let mut resolution = None;
         let mut hdcp_level = None;
         let mut video = None;
+        let mut video_range = None;
+        let mut score = None;
+        let mut stable_variant_id = None;
 
         for (key, value) in input.parse::<AttributePairs>()? {
             match key.as_str() {
@@ -137,6 +170,9 @@ impl FromStr for ExtXIFrameStreamInf {
                 "RESOLUTION" => resolution = Some(value.parse()?),
                 "HDCP-LEVEL" => hdcp_level = Some(value.parse()?),
                 "VIDEO" => video = Some(unquote(value)),
+                "VIDEO-RANGE" => video_range = Some(value.parse()?),
+                "SCORE" => score = Some(value.parse()?),
+                "STABLE-VARIANT-ID" => stable_variant_id = Some(unquote(value)),
                 _ => {
                     // [6.3.1. General Client Responsibilities]
                     // > ignore any attribute/value pair with an unrecognized AttributeName.
@@ -155,6 +191,9 @@ impl FromStr for ExtXIFrameStreamInf {
             resolution,
             hdcp_level,
             video,
+            video_range,
+            score,
+            stable_variant_id,
         })
     }
 }
@@ -190,4 +229,23 @@ mod test {
             ProtocolVersion::V1
         );
     }
+
+    #[test]
+    fn test_video_range_score_and_stable_variant_id() {
+        let text = concat!(
+            r#"#EXT-X-I-FRAME-STREAM-INF:URI="foo",BANDWIDTH=1000,"#,
+            r#"VIDEO-RANGE=PQ,SCORE=2.5,STABLE-VARIANT-ID="abcd""#
+        );
+
+        let mut i_frame_stream_inf = ExtXIFrameStreamInf::new("foo", 1000);
+        i_frame_stream_inf.set_video_range(Some(VideoRange::Pq));
+        i_frame_stream_inf.set_score(Some(DecimalFloatingPoint::new(2.5)));
+        i_frame_stream_inf.set_stable_variant_id(Some("abcd".to_string()));
+
+        assert_eq!(
+            text.parse::<ExtXIFrameStreamInf>().unwrap(),
+            i_frame_stream_inf.clone()
+        );
+        assert_eq!(i_frame_stream_inf.to_string(), text);
+    }
 }
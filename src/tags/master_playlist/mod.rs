@@ -0,0 +1,3 @@
+mod i_frame_stream_inf;
+
+pub use i_frame_stream_inf::ExtXIFrameStreamInf;
@@ -0,0 +1,7 @@
+mod part_inf;
+mod preload_hint;
+mod server_control;
+
+pub use part_inf::ExtXPartInf;
+pub use preload_hint::{ExtXPreloadHint, ExtXRenditionReport, PreloadHintType};
+pub use server_control::ExtXServerControl;
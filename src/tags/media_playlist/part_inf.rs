@@ -0,0 +1,121 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::attribute::AttributePairs;
+use crate::types::ProtocolVersion;
+use crate::utils::{parse_f64, tag};
+use crate::{Error, RequiredVersion};
+
+/// Specifies the target duration for the partial segments in a
+/// [`MediaPlaylist`], i.e. the maximum duration of any [`ExtXPart`].
+///
+/// This tag is part of the Low-Latency HLS extension and must appear if the
+/// playlist contains any [`ExtXPart`] tags.
+///
+/// [`MediaPlaylist`]: crate::MediaPlaylist
+/// [`ExtXPart`]: crate::tags::ExtXPart
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ExtXPartInf {
+    part_target: Duration,
+}
+
+impl ExtXPartInf {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-PART-INF:";
+
+    /// Makes a new [`ExtXPartInf`] tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use hls_m3u8::tags::ExtXPartInf;
+    /// use std::time::Duration;
+    ///
+    /// let part_inf = ExtXPartInf::new(Duration::from_secs_f64(0.5));
+    /// ```
+    #[must_use]
+    pub const fn new(part_target: Duration) -> Self { Self { part_target } }
+
+    /// Returns the target duration for partial segments.
+    #[must_use]
+    pub const fn part_target(&self) -> Duration { self.part_target }
+
+    /// Sets the target duration for partial segments.
+    pub fn set_part_target(&mut self, value: Duration) -> &mut Self {
+        self.part_target = value;
+        self
+    }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXPartInf {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXPartInf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}PART-TARGET={}",
+            Self::PREFIX,
+            self.part_target.as_secs_f64()
+        )
+    }
+}
+
+impl FromStr for ExtXPartInf {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = tag(input, Self::PREFIX)?;
+
+        let mut part_target = None;
+
+        for (key, value) in input.parse::<AttributePairs>()? {
+            match key.as_str() {
+                "PART-TARGET" => part_target = Some(Duration::from_secs_f64(parse_f64(value)?)),
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized
+                    // > AttributeName.
+                }
+            }
+        }
+
+        let part_target = part_target.ok_or_else(|| Error::missing_value("PART-TARGET"))?;
+
+        Ok(Self { part_target })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ExtXPartInf::new(Duration::from_secs_f64(0.5)).to_string(),
+            "#EXT-X-PART-INF:PART-TARGET=0.5".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parser() {
+        let text = "#EXT-X-PART-INF:PART-TARGET=0.5";
+
+        assert_eq!(
+            text.parse::<ExtXPartInf>().unwrap(),
+            ExtXPartInf::new(Duration::from_secs_f64(0.5))
+        );
+    }
+
+    #[test]
+    fn test_required_version() {
+        assert_eq!(
+            ExtXPartInf::new(Duration::from_secs_f64(0.5)).required_version(),
+            ProtocolVersion::V1
+        );
+    }
+}
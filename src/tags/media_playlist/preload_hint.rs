@@ -0,0 +1,333 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attribute::AttributePairs;
+use crate::types::ProtocolVersion;
+use crate::utils::{parse_u64, quote, tag, unquote};
+use crate::{Error, RequiredVersion};
+
+/// The type of resource that an [`ExtXPreloadHint`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreloadHintType {
+    /// The hinted resource is a partial segment, as produced by an
+    /// [`ExtXPart`] tag.
+    ///
+    /// [`ExtXPart`]: crate::tags::ExtXPart
+    Part,
+    /// The hinted resource is a Media Initialization Section, as produced by
+    /// an [`ExtXMap`] tag.
+    ///
+    /// [`ExtXMap`]: crate::tags::ExtXMap
+    Map,
+}
+
+impl fmt::Display for PreloadHintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Part => write!(f, "PART"),
+            Self::Map => write!(f, "MAP"),
+        }
+    }
+}
+
+impl FromStr for PreloadHintType {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "PART" => Ok(Self::Part),
+            "MAP" => Ok(Self::Map),
+            _ => Err(Error::custom(format!("invalid TYPE: {}", input))),
+        }
+    }
+}
+
+/// Allows a server to advertise that a client can begin fetching a resource
+/// before the tag that would normally announce it has been written to the
+/// playlist.
+///
+/// This tag is part of the Low-Latency HLS extension and lets a client start
+/// downloading the next partial segment (or Media Initialization Section) as
+/// soon as the server is aware it exists, rather than waiting for the
+/// playlist to be reloaded again.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExtXPreloadHint {
+    hint_type: PreloadHintType,
+    uri: String,
+    byte_range_start: Option<u64>,
+    byte_range_length: Option<u64>,
+}
+
+impl ExtXPreloadHint {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-PRELOAD-HINT:";
+
+    /// Makes a new [`ExtXPreloadHint`] tag.
+    #[must_use]
+    pub fn new<T: ToString>(hint_type: PreloadHintType, uri: T) -> Self {
+        Self {
+            hint_type,
+            uri: uri.to_string(),
+            byte_range_start: None,
+            byte_range_length: None,
+        }
+    }
+
+    /// Returns the type of resource, that is hinted at.
+    #[must_use]
+    pub const fn hint_type(&self) -> PreloadHintType { self.hint_type }
+
+    /// Returns the URI of the hinted resource.
+    #[must_use]
+    pub fn uri(&self) -> &str { &self.uri }
+
+    /// Returns the start of the byte range of the hinted resource.
+    #[must_use]
+    pub const fn byte_range_start(&self) -> Option<u64> { self.byte_range_start }
+
+    /// Sets the start of the byte range of the hinted resource.
+    pub fn set_byte_range_start(&mut self, value: Option<u64>) -> &mut Self {
+        self.byte_range_start = value;
+        self
+    }
+
+    /// Returns the length of the byte range of the hinted resource.
+    #[must_use]
+    pub const fn byte_range_length(&self) -> Option<u64> { self.byte_range_length }
+
+    /// Sets the length of the byte range of the hinted resource.
+    pub fn set_byte_range_length(&mut self, value: Option<u64>) -> &mut Self {
+        self.byte_range_length = value;
+        self
+    }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXPreloadHint {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXPreloadHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "TYPE={}", self.hint_type)?;
+        write!(f, ",URI={}", quote(&self.uri))?;
+
+        if let Some(value) = &self.byte_range_start {
+            write!(f, ",BYTERANGE-START={}", value)?;
+        }
+
+        if let Some(value) = &self.byte_range_length {
+            write!(f, ",BYTERANGE-LENGTH={}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ExtXPreloadHint {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = tag(input, Self::PREFIX)?;
+
+        let mut hint_type = None;
+        let mut uri = None;
+        let mut byte_range_start = None;
+        let mut byte_range_length = None;
+
+        for (key, value) in input.parse::<AttributePairs>()? {
+            match key.as_str() {
+                "TYPE" => hint_type = Some(value.parse()?),
+                "URI" => uri = Some(unquote(value)),
+                "BYTERANGE-START" => byte_range_start = Some(parse_u64(value)?),
+                "BYTERANGE-LENGTH" => byte_range_length = Some(parse_u64(value)?),
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized
+                    // > AttributeName.
+                }
+            }
+        }
+
+        let hint_type = hint_type.ok_or_else(|| Error::missing_value("TYPE"))?;
+        let uri = uri.ok_or_else(|| Error::missing_value("URI"))?;
+
+        Ok(Self {
+            hint_type,
+            uri,
+            byte_range_start,
+            byte_range_length,
+        })
+    }
+}
+
+/// Allows a server to advertise an endpoint from which a client can retrieve
+/// an up-to-date rendition report for another rendition of the same
+/// presentation.
+///
+/// This tag is part of the Low-Latency HLS extension and lets a client
+/// discover the latest Media Sequence Number and Part Sequence Number of a
+/// rendition without having to reload its playlist speculatively.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExtXRenditionReport {
+    uri: String,
+    last_msn: Option<u64>,
+    last_part: Option<u64>,
+}
+
+impl ExtXRenditionReport {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-RENDITION-REPORT:";
+
+    /// Makes a new [`ExtXRenditionReport`] tag.
+    #[must_use]
+    pub fn new<T: ToString>(uri: T) -> Self {
+        Self {
+            uri: uri.to_string(),
+            last_msn: None,
+            last_part: None,
+        }
+    }
+
+    /// Returns the URI of the media playlist, that contains the rendition
+    /// report.
+    #[must_use]
+    pub fn uri(&self) -> &str { &self.uri }
+
+    /// Returns the Media Sequence Number of the last low-latency segment
+    /// currently in the reported rendition.
+    #[must_use]
+    pub const fn last_msn(&self) -> Option<u64> { self.last_msn }
+
+    /// Sets the Media Sequence Number of the last low-latency segment
+    /// currently in the reported rendition.
+    pub fn set_last_msn(&mut self, value: Option<u64>) -> &mut Self {
+        self.last_msn = value;
+        self
+    }
+
+    /// Returns the Part Sequence Number of the last partial segment
+    /// currently in the reported rendition.
+    #[must_use]
+    pub const fn last_part(&self) -> Option<u64> { self.last_part }
+
+    /// Sets the Part Sequence Number of the last partial segment currently in
+    /// the reported rendition.
+    pub fn set_last_part(&mut self, value: Option<u64>) -> &mut Self {
+        self.last_part = value;
+        self
+    }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXRenditionReport {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXRenditionReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "URI={}", quote(&self.uri))?;
+
+        if let Some(value) = &self.last_msn {
+            write!(f, ",LAST-MSN={}", value)?;
+        }
+
+        if let Some(value) = &self.last_part {
+            write!(f, ",LAST-PART={}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ExtXRenditionReport {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = tag(input, Self::PREFIX)?;
+
+        let mut uri = None;
+        let mut last_msn = None;
+        let mut last_part = None;
+
+        for (key, value) in input.parse::<AttributePairs>()? {
+            match key.as_str() {
+                "URI" => uri = Some(unquote(value)),
+                "LAST-MSN" => last_msn = Some(parse_u64(value)?),
+                "LAST-PART" => last_part = Some(parse_u64(value)?),
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized
+                    // > AttributeName.
+                }
+            }
+        }
+
+        let uri = uri.ok_or_else(|| Error::missing_value("URI"))?;
+
+        Ok(Self {
+            uri,
+            last_msn,
+            last_part,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_preload_hint_display() {
+        assert_eq!(
+            ExtXPreloadHint::new(PreloadHintType::Part, "part.5.mp4").to_string(),
+            "#EXT-X-PRELOAD-HINT:TYPE=PART,URI=\"part.5.mp4\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_preload_hint_parser() {
+        let text = r#"#EXT-X-PRELOAD-HINT:TYPE=PART,URI="part.5.mp4""#;
+
+        assert_eq!(
+            text.parse::<ExtXPreloadHint>().unwrap(),
+            ExtXPreloadHint::new(PreloadHintType::Part, "part.5.mp4")
+        );
+    }
+
+    #[test]
+    fn test_preload_hint_required_version() {
+        assert_eq!(
+            ExtXPreloadHint::new(PreloadHintType::Map, "init.mp4").required_version(),
+            ProtocolVersion::V1
+        );
+    }
+
+    #[test]
+    fn test_rendition_report_display() {
+        assert_eq!(
+            ExtXRenditionReport::new("low.m3u8").to_string(),
+            "#EXT-X-RENDITION-REPORT:URI=\"low.m3u8\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_rendition_report_parser() {
+        let text = r#"#EXT-X-RENDITION-REPORT:URI="low.m3u8",LAST-MSN=100,LAST-PART=2"#;
+
+        let mut report = ExtXRenditionReport::new("low.m3u8");
+        report.set_last_msn(Some(100));
+        report.set_last_part(Some(2));
+
+        assert_eq!(text.parse::<ExtXRenditionReport>().unwrap(), report);
+    }
+
+    #[test]
+    fn test_rendition_report_required_version() {
+        assert_eq!(
+            ExtXRenditionReport::new("low.m3u8").required_version(),
+            ProtocolVersion::V1
+        );
+    }
+}
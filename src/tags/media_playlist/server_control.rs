@@ -0,0 +1,225 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::attribute::AttributePairs;
+use crate::types::ProtocolVersion;
+use crate::utils::{parse_f64, tag};
+use crate::{Error, RequiredVersion};
+
+/// Allows a server to indicate to a client how it should handle reloading a
+/// live [`MediaPlaylist`].
+///
+/// This tag is part of the Low-Latency HLS extension. It lets a server
+/// advertise a hold-back and a Blocking Playlist Reload window so that a
+/// client does not poll for an updated playlist more often than the server
+/// is able to produce one.
+///
+/// [`MediaPlaylist`]: crate::MediaPlaylist
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ExtXServerControl {
+    can_skip_until: Option<Duration>,
+    can_skip_dateranges: bool,
+    hold_back: Option<Duration>,
+    part_hold_back: Option<Duration>,
+    can_block_reload: bool,
+}
+
+impl ExtXServerControl {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-SERVER-CONTROL:";
+
+    /// Makes a new [`ExtXServerControl`] tag.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            can_skip_until: None,
+            can_skip_dateranges: false,
+            hold_back: None,
+            part_hold_back: None,
+            can_block_reload: false,
+        }
+    }
+
+    /// Returns the duration, that a server must keep a Playlist Delta Update
+    /// available for, i.e. the maximum distance from the end of a playlist
+    /// that a client may skip to using `CAN-SKIP-UNTIL`.
+    #[must_use]
+    pub const fn can_skip_until(&self) -> Option<Duration> { self.can_skip_until }
+
+    /// Sets the `CAN-SKIP-UNTIL` attribute.
+    pub fn set_can_skip_until(&mut self, value: Option<Duration>) -> &mut Self {
+        self.can_skip_until = value;
+        self
+    }
+
+    /// Returns whether the server supports skipping of `EXT-X-DATERANGE`
+    /// tags in a Playlist Delta Update.
+    #[must_use]
+    pub const fn can_skip_dateranges(&self) -> bool { self.can_skip_dateranges }
+
+    /// Sets the `CAN-SKIP-DATERANGES` attribute.
+    pub fn set_can_skip_dateranges(&mut self, value: bool) -> &mut Self {
+        self.can_skip_dateranges = value;
+        self
+    }
+
+    /// Returns the server-recommended minimum distance from the end of the
+    /// playlist, at which clients should begin to play.
+    #[must_use]
+    pub const fn hold_back(&self) -> Option<Duration> { self.hold_back }
+
+    /// Sets the `HOLD-BACK` attribute.
+    pub fn set_hold_back(&mut self, value: Option<Duration>) -> &mut Self {
+        self.hold_back = value;
+        self
+    }
+
+    /// Returns the server-recommended minimum distance from the end of the
+    /// playlist, at which clients should begin to play, when playing partial
+    /// segments.
+    #[must_use]
+    pub const fn part_hold_back(&self) -> Option<Duration> { self.part_hold_back }
+
+    /// Sets the `PART-HOLD-BACK` attribute.
+    pub fn set_part_hold_back(&mut self, value: Option<Duration>) -> &mut Self {
+        self.part_hold_back = value;
+        self
+    }
+
+    /// Returns whether the server supports Blocking Playlist Reload.
+    #[must_use]
+    pub const fn can_block_reload(&self) -> bool { self.can_block_reload }
+
+    /// Sets the `CAN-BLOCK-RELOAD` attribute.
+    pub fn set_can_block_reload(&mut self, value: bool) -> &mut Self {
+        self.can_block_reload = value;
+        self
+    }
+}
+
+impl Default for ExtXServerControl {
+    fn default() -> Self { Self::new() }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXServerControl {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXServerControl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+
+        let mut has_attribute = false;
+
+        if let Some(value) = &self.can_skip_until {
+            write!(f, "CAN-SKIP-UNTIL={}", value.as_secs_f64())?;
+            has_attribute = true;
+        }
+
+        if self.can_skip_dateranges {
+            if has_attribute {
+                write!(f, ",")?;
+            }
+            write!(f, "CAN-SKIP-DATERANGES=YES")?;
+            has_attribute = true;
+        }
+
+        if let Some(value) = &self.hold_back {
+            if has_attribute {
+                write!(f, ",")?;
+            }
+            write!(f, "HOLD-BACK={}", value.as_secs_f64())?;
+            has_attribute = true;
+        }
+
+        if let Some(value) = &self.part_hold_back {
+            if has_attribute {
+                write!(f, ",")?;
+            }
+            write!(f, "PART-HOLD-BACK={}", value.as_secs_f64())?;
+            has_attribute = true;
+        }
+
+        if self.can_block_reload {
+            if has_attribute {
+                write!(f, ",")?;
+            }
+            write!(f, "CAN-BLOCK-RELOAD=YES")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ExtXServerControl {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = tag(input, Self::PREFIX)?;
+
+        let mut server_control = Self::new();
+
+        for (key, value) in input.parse::<AttributePairs>()? {
+            match key.as_str() {
+                "CAN-SKIP-UNTIL" => {
+                    server_control.can_skip_until = Some(Duration::from_secs_f64(parse_f64(value)?))
+                }
+                "CAN-SKIP-DATERANGES" => server_control.can_skip_dateranges = value == "YES",
+                "HOLD-BACK" => {
+                    server_control.hold_back = Some(Duration::from_secs_f64(parse_f64(value)?))
+                }
+                "PART-HOLD-BACK" => {
+                    server_control.part_hold_back = Some(Duration::from_secs_f64(parse_f64(value)?))
+                }
+                "CAN-BLOCK-RELOAD" => server_control.can_block_reload = value == "YES",
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized
+                    // > AttributeName.
+                }
+            }
+        }
+
+        Ok(server_control)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        let mut server_control = ExtXServerControl::new();
+        server_control.set_can_block_reload(true);
+        server_control.set_hold_back(Some(Duration::from_secs(6)));
+
+        assert_eq!(
+            server_control.to_string(),
+            "#EXT-X-SERVER-CONTROL:HOLD-BACK=6,CAN-BLOCK-RELOAD=YES".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parser() {
+        let text = "#EXT-X-SERVER-CONTROL:CAN-SKIP-UNTIL=12,HOLD-BACK=6,PART-HOLD-BACK=1.5,CAN-BLOCK-RELOAD=YES";
+
+        let mut server_control = ExtXServerControl::new();
+        server_control.set_can_skip_until(Some(Duration::from_secs(12)));
+        server_control.set_hold_back(Some(Duration::from_secs(6)));
+        server_control.set_part_hold_back(Some(Duration::from_secs_f64(1.5)));
+        server_control.set_can_block_reload(true);
+
+        assert_eq!(text.parse::<ExtXServerControl>().unwrap(), server_control);
+    }
+
+    #[test]
+    fn test_required_version() {
+        assert_eq!(
+            ExtXServerControl::new().required_version(),
+            ProtocolVersion::V1
+        );
+    }
+}
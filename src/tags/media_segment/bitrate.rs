@@ -0,0 +1,69 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::ProtocolVersion;
+use crate::utils::{parse_u64, tag};
+use crate::{Error, RequiredVersion};
+
+/// Indicates the approximate segment bit rate of a [`MediaSegment`].
+///
+/// This tag applies to the `MediaSegment` it precedes, as well as every
+/// following `MediaSegment` until the next [`ExtXBitrate`] tag, and is most
+/// useful for CMAF/fMP4 content where the `BANDWIDTH` of the variant stream
+/// alone is too coarse for clients to budget a download.
+///
+/// [`MediaSegment`]: crate::MediaSegment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtXBitrate(u64);
+
+impl ExtXBitrate {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-BITRATE:";
+
+    /// Makes a new [`ExtXBitrate`] tag, where `kbps` is the approximate
+    /// segment bit rate in kilobits per second.
+    #[must_use]
+    pub const fn new(kbps: u64) -> Self { Self(kbps) }
+
+    /// Returns the approximate segment bit rate in kilobits per second.
+    #[must_use]
+    pub const fn kbps(&self) -> u64 { self.0 }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXBitrate {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXBitrate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}{}", Self::PREFIX, self.0) }
+}
+
+impl FromStr for ExtXBitrate {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = tag(input, Self::PREFIX)?;
+        Ok(Self(parse_u64(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(ExtXBitrate::new(500).to_string(), "#EXT-X-BITRATE:500".to_string());
+    }
+
+    #[test]
+    fn test_parser() {
+        assert_eq!("#EXT-X-BITRATE:500".parse::<ExtXBitrate>().unwrap(), ExtXBitrate::new(500));
+    }
+
+    #[test]
+    fn test_required_version() {
+        assert_eq!(ExtXBitrate::new(500).required_version(), ProtocolVersion::V1);
+    }
+}
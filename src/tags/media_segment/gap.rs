@@ -0,0 +1,64 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::ProtocolVersion;
+use crate::{Error, RequiredVersion};
+
+/// Indicates that the [`MediaSegment`] it precedes does not contain media
+/// data and should not be loaded by clients.
+///
+/// This allows a server to signal a gap in the content, for example during a
+/// content-replacement window, so that downstream players skip the fetch
+/// instead of erroring on a missing resource.
+///
+/// [`MediaSegment`]: crate::MediaSegment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtXGap;
+
+impl ExtXGap {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-GAP";
+
+    /// Makes a new [`ExtXGap`] tag.
+    #[must_use]
+    pub const fn new() -> Self { Self }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXGap {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXGap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", Self::PREFIX) }
+}
+
+impl FromStr for ExtXGap {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input == Self::PREFIX {
+            Ok(Self)
+        } else {
+            Err(Error::custom(format!("invalid tag: {}", input)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() { assert_eq!(ExtXGap::new().to_string(), "#EXT-X-GAP".to_string()); }
+
+    #[test]
+    fn test_parser() {
+        assert_eq!("#EXT-X-GAP".parse::<ExtXGap>().unwrap(), ExtXGap::new());
+    }
+
+    #[test]
+    fn test_required_version() {
+        assert_eq!(ExtXGap::new().required_version(), ProtocolVersion::V1);
+    }
+}
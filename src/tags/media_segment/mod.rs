@@ -0,0 +1,9 @@
+mod bitrate;
+mod gap;
+mod map;
+mod part;
+
+pub use bitrate::ExtXBitrate;
+pub use gap::ExtXGap;
+pub use map::ExtXMap;
+pub use part::ExtXPart;
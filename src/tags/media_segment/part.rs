@@ -0,0 +1,206 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::attribute::AttributePairs;
+use crate::types::{ByteRange, ProtocolVersion};
+use crate::utils::{parse_f64, quote, tag, unquote};
+use crate::{Error, RequiredVersion};
+
+/// Identifies a Partial Segment, which is either a sub-range of a
+/// [`MediaSegment`]'s resource or a resource that will later be merged with
+/// other partial segments into a complete `MediaSegment`.
+///
+/// This tag is part of the Low-Latency HLS extension and allows a segment to
+/// be published and fetched before it has been fully encoded, reducing the
+/// latency between encoding and playback.
+///
+/// [`MediaSegment`]: crate::MediaSegment
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtXPart {
+    uri: String,
+    duration: Duration,
+    independent: bool,
+    byte_range: Option<ByteRange>,
+    gap: bool,
+}
+
+impl ExtXPart {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-PART:";
+
+    /// Makes a new [`ExtXPart`] tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use hls_m3u8::tags::ExtXPart;
+    /// use std::time::Duration;
+    ///
+    /// let part = ExtXPart::new("part.ts", Duration::from_secs_f64(0.5));
+    /// ```
+    #[must_use]
+    pub fn new<T: ToString>(uri: T, duration: Duration) -> Self {
+        Self {
+            uri: uri.to_string(),
+            duration,
+            independent: false,
+            byte_range: None,
+            gap: false,
+        }
+    }
+
+    /// Returns the URI of the partial segment.
+    #[must_use]
+    pub fn uri(&self) -> &str { &self.uri }
+
+    /// Returns the duration of the partial segment.
+    #[must_use]
+    pub const fn duration(&self) -> Duration { self.duration }
+
+    /// Returns whether this partial segment contains an independent frame.
+    #[must_use]
+    pub const fn independent(&self) -> bool { self.independent }
+
+    /// Sets whether this partial segment contains an independent frame.
+    pub fn set_independent(&mut self, value: bool) -> &mut Self {
+        self.independent = value;
+        self
+    }
+
+    /// Returns the sub-range of the resource that this partial segment
+    /// represents.
+    #[must_use]
+    pub const fn byte_range(&self) -> Option<ByteRange> { self.byte_range }
+
+    /// Sets the sub-range of the resource that this partial segment
+    /// represents.
+    pub fn set_byte_range(&mut self, value: Option<ByteRange>) -> &mut Self {
+        self.byte_range = value;
+        self
+    }
+
+    /// Returns whether the partial segment's resource is unavailable.
+    #[must_use]
+    pub const fn gap(&self) -> bool { self.gap }
+
+    /// Sets whether the partial segment's resource is unavailable.
+    pub fn set_gap(&mut self, value: bool) -> &mut Self {
+        self.gap = value;
+        self
+    }
+}
+
+/// This tag requires [`ProtocolVersion::V1`].
+impl RequiredVersion for ExtXPart {
+    fn required_version(&self) -> ProtocolVersion { ProtocolVersion::V1 }
+}
+
+impl fmt::Display for ExtXPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "URI={}", quote(&self.uri))?;
+        write!(f, ",DURATION={}", self.duration.as_secs_f64())?;
+
+        if self.independent {
+            write!(f, ",INDEPENDENT=YES")?;
+        }
+
+        if let Some(value) = &self.byte_range {
+            write!(f, ",BYTERANGE={}", quote(value))?;
+        }
+
+        if self.gap {
+            write!(f, ",GAP=YES")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ExtXPart {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = tag(input, Self::PREFIX)?;
+
+        let mut uri = None;
+        let mut duration = None;
+        let mut independent = false;
+        let mut byte_range = None;
+        let mut gap = false;
+
+        for (key, value) in input.parse::<AttributePairs>()? {
+            match key.as_str() {
+                "URI" => uri = Some(unquote(value)),
+                "DURATION" => duration = Some(Duration::from_secs_f64(parse_f64(value)?)),
+                "INDEPENDENT" => independent = value == "YES",
+                "BYTERANGE" => byte_range = Some(unquote(value).parse()?),
+                "GAP" => gap = value == "YES",
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized
+                    // > AttributeName.
+                }
+            }
+        }
+
+        let uri = uri.ok_or_else(|| Error::missing_value("URI"))?;
+        let duration = duration.ok_or_else(|| Error::missing_value("DURATION"))?;
+
+        Ok(Self {
+            uri,
+            duration,
+            independent,
+            byte_range,
+            gap,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ExtXPart::new("part.1.mp4", Duration::from_secs_f64(0.5)).to_string(),
+            "#EXT-X-PART:URI=\"part.1.mp4\",DURATION=0.5".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parser() {
+        let text = r#"#EXT-X-PART:URI="part.1.mp4",DURATION=0.5,INDEPENDENT=YES"#;
+
+        let mut part = ExtXPart::new("part.1.mp4", Duration::from_secs_f64(0.5));
+        part.set_independent(true);
+
+        assert_eq!(text.parse::<ExtXPart>().unwrap(), part);
+    }
+
+    #[test]
+    fn test_parser_with_byte_range_and_gap() {
+        let text = concat!(
+            "#EXT-X-PART:URI=\"part.2.mp4\",DURATION=0.5,INDEPENDENT=YES,",
+            "BYTERANGE=\"500@0\",GAP=YES"
+        );
+
+        let mut part = ExtXPart::new("part.2.mp4", Duration::from_secs_f64(0.5));
+        part.set_independent(true);
+        part.set_byte_range(Some(ByteRange::from(0..500)));
+        part.set_gap(true);
+
+        assert_eq!(text.parse::<ExtXPart>().unwrap(), part);
+        assert_eq!(part.to_string(), text.to_string());
+    }
+
+    #[test]
+    fn test_required_version() {
+        assert_eq!(
+            ExtXPart::new("part.1.mp4", Duration::from_secs_f64(0.5)).required_version(),
+            ProtocolVersion::V1
+        );
+    }
+}
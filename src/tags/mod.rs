@@ -0,0 +1,7 @@
+mod master_playlist;
+mod media_playlist;
+mod media_segment;
+
+pub use master_playlist::*;
+pub use media_playlist::*;
+pub use media_segment::*;
@@ -0,0 +1,95 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// A floating-point number, that is wrapped so it can be compared and hashed
+/// consistently, unlike a bare [`f64`].
+///
+/// This is used for attributes such as `SCORE` on [`ExtXIFrameStreamInf`],
+/// which are decimal values but still need to participate in `Eq`/`Hash`
+/// derives alongside a tag's other attributes.
+///
+/// [`ExtXIFrameStreamInf`]: crate::tags::ExtXIFrameStreamInf
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalFloatingPoint(f64);
+
+impl DecimalFloatingPoint {
+    /// Makes a new [`DecimalFloatingPoint`].
+    #[must_use]
+    pub const fn new(value: f64) -> Self { Self(value) }
+
+    /// Returns the wrapped value as an [`f64`].
+    #[must_use]
+    pub const fn as_f64(&self) -> f64 { self.0 }
+}
+
+impl PartialEq for DecimalFloatingPoint {
+    fn eq(&self, other: &Self) -> bool { self.0.to_bits() == other.0.to_bits() }
+}
+
+impl Eq for DecimalFloatingPoint {}
+
+impl std::hash::Hash for DecimalFloatingPoint {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.0.to_bits().hash(state) }
+}
+
+// NOTE: `PartialOrd`/`Ord` are hand-rolled via `f64::total_cmp` instead of
+// derived, so that they stay consistent with the `to_bits()`-based
+// `PartialEq`/`Hash` above (a derived `PartialOrd` would compare the raw
+// `f64` and disagree with `==` on e.g. `0.0` vs `-0.0`).
+impl PartialOrd for DecimalFloatingPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for DecimalFloatingPoint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.0.total_cmp(&other.0) }
+}
+
+impl From<f64> for DecimalFloatingPoint {
+    fn from(value: f64) -> Self { Self(value) }
+}
+
+impl fmt::Display for DecimalFloatingPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.0) }
+}
+
+impl FromStr for DecimalFloatingPoint {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            input
+                .parse::<f64>()
+                .map_err(|_| Error::custom(format!("invalid decimal-floating-point: {}", input)))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(DecimalFloatingPoint::new(2.5).to_string(), "2.5".to_string());
+    }
+
+    #[test]
+    fn test_parser() {
+        assert_eq!(
+            "2.5".parse::<DecimalFloatingPoint>().unwrap(),
+            DecimalFloatingPoint::new(2.5)
+        );
+    }
+
+    #[test]
+    fn test_eq_and_ord_agree_on_signed_zero() {
+        let zero = DecimalFloatingPoint::new(0.0);
+        let neg_zero = DecimalFloatingPoint::new(-0.0);
+
+        assert_ne!(zero, neg_zero);
+        assert_ne!(zero.partial_cmp(&neg_zero), Some(std::cmp::Ordering::Equal));
+    }
+}
@@ -0,0 +1,5 @@
+mod decimal_floating_point;
+mod video_range;
+
+pub use decimal_floating_point::DecimalFloatingPoint;
+pub use video_range::VideoRange;
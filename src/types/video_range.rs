@@ -0,0 +1,63 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// The video dynamic range used by the video in a variant stream.
+///
+/// A value of [`VideoRange::Sdr`] should be used to indicate the variant is
+/// compatible with the most widely deployed decoding and rendering pipelines,
+/// while [`VideoRange::Pq`] and [`VideoRange::Hlg`] indicate HDR content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VideoRange {
+    /// Standard Dynamic Range.
+    Sdr,
+    /// Hybrid Log-Gamma.
+    Hlg,
+    /// Perceptual Quantizer.
+    Pq,
+}
+
+impl fmt::Display for VideoRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Sdr => write!(f, "SDR"),
+            Self::Hlg => write!(f, "HLG"),
+            Self::Pq => write!(f, "PQ"),
+        }
+    }
+}
+
+impl FromStr for VideoRange {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "SDR" => Ok(Self::Sdr),
+            "HLG" => Ok(Self::Hlg),
+            "PQ" => Ok(Self::Pq),
+            _ => Err(Error::custom(format!("invalid VIDEO-RANGE: {}", input))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(VideoRange::Sdr.to_string(), "SDR".to_string());
+        assert_eq!(VideoRange::Hlg.to_string(), "HLG".to_string());
+        assert_eq!(VideoRange::Pq.to_string(), "PQ".to_string());
+    }
+
+    #[test]
+    fn test_parser() {
+        assert_eq!("SDR".parse::<VideoRange>().unwrap(), VideoRange::Sdr);
+        assert_eq!("HLG".parse::<VideoRange>().unwrap(), VideoRange::Hlg);
+        assert_eq!("PQ".parse::<VideoRange>().unwrap(), VideoRange::Pq);
+        assert!("FOO".parse::<VideoRange>().is_err());
+    }
+}